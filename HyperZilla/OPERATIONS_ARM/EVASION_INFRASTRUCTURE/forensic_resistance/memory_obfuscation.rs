@@ -1,52 +1,226 @@
 // ~/HyperZilla/OPERATIONS_ARM/EVASION_INFRASTRUCTURE/forensic_resistance/memory_obfuscation.rs
-use std::ptr;
+use std::fs;
 use std::mem;
-use libc::{mprotect, PROT_READ, PROT_WRITE, PROT_EXEC};
-use rand::Rng;
+use std::ptr;
+use std::slice;
 
-pub struct MemoryObfuscator {
-    encryption_key: [u8; 32],
-    anti_analysis: bool,
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use libc::{c_void, mprotect, PROT_NONE, PROT_READ, PROT_WRITE};
+use rand::RngCore;
+
+const PAGE_SIZE: usize = 4096;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// True when a tracer (debugger, `strace`, etc.) is attached to this process.
+/// Reads `TracerPid` out of `/proc/self/status` rather than probing with
+/// `PTRACE_TRACEME`: that call only attaches a tracer on its *first* success,
+/// so every call after the first would observe ourselves as traced and this
+/// predicate would never again return `false`.
+fn tracer_attached() -> bool {
+    let status = match fs::read_to_string("/proc/self/status") {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("TracerPid:"))
+        .and_then(|pid| pid.trim().parse::<u32>().ok())
+        .map(|pid| pid != 0)
+        .unwrap_or(false)
 }
 
-impl MemoryObfuscator {
-    pub fn new() -> Self {
-        MemoryObfuscator {
-            encryption_key: rand::thread_rng().gen(),
-            anti_analysis: true,
+/// A heap region that holds sensitive bytes encrypted at rest. While sealed,
+/// the backing page(s) are `mprotect`'d to `PROT_NONE` so even a successful
+/// read primitive in the host process gets nothing but ciphertext (and can't
+/// read it at all without faulting). `unseal` hands back the plaintext only
+/// after the AEAD tag has verified.
+pub struct SecretRegion {
+    ptr: *mut u8,
+    len: usize,
+    alloc_len: usize,
+    key: [u8; 32],
+    nonce: Option<[u8; NONCE_LEN]>,
+    tag: Option<[u8; TAG_LEN]>,
+    sealed: bool,
+}
+
+impl SecretRegion {
+    /// Copies `plaintext` into a fresh, page-aligned anonymous mapping and
+    /// generates a random 32-byte key for it. The region starts unsealed;
+    /// call `seal()` to encrypt and lock it down.
+    pub fn new(plaintext: &[u8]) -> Result<Self, String> {
+        let pages = plaintext.len().max(1).div_ceil(PAGE_SIZE);
+        let alloc_len = pages * PAGE_SIZE;
+
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                alloc_len,
+                PROT_READ | PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err("mmap failed to allocate secret region".to_string());
         }
-    }
-    
-    pub fn encrypt_in_memory(&self, data: &mut [u8]) {
-        // XOR encryption with random key
-        for byte in data.iter_mut() {
-            *byte ^= self.encryption_key[rand::thread_rng().gen_range(0..32)];
+        let ptr = ptr as *mut u8;
+
+        unsafe {
+            ptr::copy_nonoverlapping(plaintext.as_ptr(), ptr, plaintext.len());
         }
+
+        let mut key = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut key);
+
+        Ok(Self {
+            ptr,
+            len: plaintext.len(),
+            alloc_len,
+            key,
+            nonce: None,
+            tag: None,
+            sealed: false,
+        })
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.sealed
     }
-    
-    pub fn hide_memory_pages(&self) -> Result<(), String> {
-        // Make memory pages non-readable to forensic tools
+
+    /// Encrypts the plaintext in place under a freshly generated nonce and
+    /// mprotects the backing page(s) to `PROT_NONE`. A no-op if already
+    /// sealed.
+    pub fn seal(&mut self) -> Result<(), String> {
+        if self.sealed {
+            return Ok(());
+        }
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.key));
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let plaintext = unsafe { slice::from_raw_parts(self.ptr, self.len) };
+        let sealed = cipher
+            .encrypt(nonce, plaintext)
+            .map_err(|_| "encryption failed".to_string())?;
+
+        let (ciphertext, tag) = sealed.split_at(self.len);
+        let mut tag_bytes = [0u8; TAG_LEN];
+        tag_bytes.copy_from_slice(tag);
+
         unsafe {
-            let page_size = 4096;
-            let mut address = self as *const _ as *mut libc::c_void;
-            
-            if libc::mprotect(address, page_size, PROT_NONE) == -1 {
-                return Err("Failed to hide memory pages".to_string());
+            ptr::copy_nonoverlapping(ciphertext.as_ptr(), self.ptr, self.len);
+            if mprotect(self.ptr as *mut c_void, self.alloc_len, PROT_NONE) != 0 {
+                return Err("mprotect(PROT_NONE) failed".to_string());
             }
         }
-        
+
+        self.nonce = Some(nonce_bytes);
+        self.tag = Some(tag_bytes);
+        self.sealed = true;
         Ok(())
     }
-    
-    pub fn detect_debugger(&self) -> bool {
-        // Anti-debugging techniques
+
+    /// Restores read/write access, verifies the AEAD tag, and decrypts in
+    /// place. Refuses to proceed while a tracer is attached, and re-seals
+    /// (rather than leaving plaintext exposed) if authentication fails.
+    pub fn unseal(&mut self) -> Result<&[u8], String> {
+        if !self.sealed {
+            return Ok(unsafe { slice::from_raw_parts(self.ptr, self.len) });
+        }
+
+        if tracer_attached() {
+            return Err("refusing to unseal: a tracer is attached to this process".to_string());
+        }
+
+        let (nonce_bytes, tag_bytes) = match (self.nonce, self.tag) {
+            (Some(n), Some(t)) => (n, t),
+            _ => return Err("sealed region is missing its nonce/tag".to_string()),
+        };
+
         unsafe {
-            // Check for debugger via ptrace
-            if libc::ptrace(libc::PTRACE_TRACEME, 0, 1, 0) == -1 {
-                return true;
+            if mprotect(self.ptr as *mut c_void, self.alloc_len, PROT_READ | PROT_WRITE) != 0 {
+                return Err("mprotect(PROT_READ|PROT_WRITE) failed".to_string());
             }
         }
-        false
+
+        let mut combined = Vec::with_capacity(self.len + TAG_LEN);
+        unsafe {
+            combined.extend_from_slice(slice::from_raw_parts(self.ptr, self.len));
+        }
+        combined.extend_from_slice(&tag_bytes);
+
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.key));
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let plaintext = match cipher.decrypt(nonce, combined.as_slice()) {
+            Ok(p) => p,
+            Err(_) => {
+                // Authentication failed: don't leave the page readable with
+                // whatever garbage is currently in it.
+                unsafe {
+                    mprotect(self.ptr as *mut c_void, self.alloc_len, PROT_NONE);
+                }
+                return Err("authentication failed: ciphertext has been tampered with".to_string());
+            }
+        };
+
+        unsafe {
+            ptr::copy_nonoverlapping(plaintext.as_ptr(), self.ptr, self.len);
+        }
+
+        self.nonce = None;
+        self.tag = None;
+        self.sealed = false;
+        Ok(unsafe { slice::from_raw_parts(self.ptr, self.len) })
+    }
+}
+
+impl Drop for SecretRegion {
+    fn drop(&mut self) {
+        unsafe {
+            // Need write access to zero the page, regardless of seal state.
+            mprotect(self.ptr as *mut c_void, self.alloc_len, PROT_READ | PROT_WRITE);
+            ptr::write_bytes(self.ptr, 0, self.alloc_len);
+            libc::munmap(self.ptr as *mut c_void, self.alloc_len);
+        }
+        secure_erase(&mut self.key);
+    }
+}
+
+pub struct MemoryObfuscator {
+    anti_analysis: bool,
+}
+
+impl Default for MemoryObfuscator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryObfuscator {
+    pub fn new() -> Self {
+        MemoryObfuscator {
+            anti_analysis: true,
+        }
+    }
+
+    /// Wraps `plaintext` in a sealed `SecretRegion`.
+    pub fn seal(&self, plaintext: &[u8]) -> Result<SecretRegion, String> {
+        let mut region = SecretRegion::new(plaintext)?;
+        region.seal()?;
+        Ok(region)
+    }
+
+    pub fn detect_debugger(&self) -> bool {
+        self.anti_analysis && tracer_attached()
     }
 }
 
@@ -1,8 +1,11 @@
 #![allow(unused_imports, dead_code, unused_variables)]
 use std::fs;
-use std::path::Path;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkConfig {
     pub default_interface: String,
     pub scan_threads: u32,
@@ -10,7 +13,7 @@ pub struct NetworkConfig {
     pub monitor_mode: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityConfig {
     pub vulnerability_db_path: String,
     pub risk_threshold: String,
@@ -18,7 +21,7 @@ pub struct SecurityConfig {
     pub enable_nessus: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReportingConfig {
     pub company_name: String,
     pub report_template: String,
@@ -27,15 +30,17 @@ pub struct ReportingConfig {
     pub enable_html: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiConfig {
     pub metasploit_host: String,
     pub metasploit_port: u16,
+    pub metasploit_user: String,
+    pub metasploit_password: String,
     pub nessus_host: String,
     pub nessus_port: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub network: NetworkConfig,
     pub security: SecurityConfig,
@@ -68,6 +73,8 @@ impl Default for AppConfig {
             api: ApiConfig {
                 metasploit_host: "127.0.0.1".to_string(),
                 metasploit_port: 55553,
+                metasploit_user: "msf".to_string(),
+                metasploit_password: "msf".to_string(),
                 nessus_host: "127.0.0.1".to_string(),
                 nessus_port: 8834,
             },
@@ -76,13 +83,43 @@ impl Default for AppConfig {
 }
 
 impl AppConfig {
-    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
-        // For now, just return default config
-        Ok(AppConfig::default())
+    /// `~/.config/entynet/config.yml`, the path `load`/`save` fall back to when
+    /// no override is given.
+    pub fn default_path() -> PathBuf {
+        dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".config")
+            .join("entynet")
+            .join("config.yml")
+    }
+
+    /// Load config from `path`, or from [`AppConfig::default_path`] when `path` is
+    /// `None`. A missing file is not an error: it yields `AppConfig::default()` so
+    /// first-run behaves the same as before this existed.
+    pub fn load(path: Option<&Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.map(Path::to_path_buf).unwrap_or_else(Self::default_path);
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        let config: AppConfig = serde_yaml::from_str(&contents)?;
+        Ok(config)
     }
 
-    pub fn save(&self, _path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        println!("Saving config");
+    /// Persist config as YAML to `path`, or to [`AppConfig::default_path`] when
+    /// `path` is `None`. Creates parent directories as needed.
+    pub fn save(&self, path: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+        let path = path.map(Path::to_path_buf).unwrap_or_else(Self::default_path);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let yaml = serde_yaml::to_string(self)?;
+        fs::write(&path, yaml)?;
+        println!("✅ Config saved to {}", path.display());
         Ok(())
     }
 
@@ -93,3 +130,101 @@ impl AppConfig {
         Ok(())
     }
 }
+
+/// Prompt for a single field, showing `current` as the default. An empty
+/// response (just pressing Enter) keeps `current`.
+fn prompt_field(label: &str, current: &str) -> Result<String, Box<dyn std::error::Error>> {
+    print!("{} [{}]: ", label, current);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+
+    if input.is_empty() {
+        Ok(current.to_string())
+    } else {
+        Ok(input.to_string())
+    }
+}
+
+fn prompt_bool(label: &str, current: bool) -> Result<bool, Box<dyn std::error::Error>> {
+    let default_str = if current { "y" } else { "n" };
+    loop {
+        let answer = prompt_field(&format!("{} (y/n)", label), default_str)?;
+        match answer.to_lowercase().as_str() {
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => println!("Please answer y or n."),
+        }
+    }
+}
+
+fn prompt_parsed<T: std::str::FromStr + std::fmt::Display>(
+    label: &str,
+    current: T,
+) -> Result<T, Box<dyn std::error::Error>> {
+    loop {
+        let answer = prompt_field(label, &current.to_string())?;
+        match answer.parse() {
+            Ok(value) => return Ok(value),
+            Err(_) => println!("Invalid value, please try again."),
+        }
+    }
+}
+
+/// Interactive `config wizard`: walks through every field of `current`,
+/// defaulting each prompt to its existing value, validates the result and
+/// returns it for the caller to persist.
+pub async fn run_wizard(current: &AppConfig) -> Result<AppConfig, Box<dyn std::error::Error>> {
+    println!("⚙️  Entynet configuration wizard");
+    println!("Press Enter to keep the current value shown in brackets.\n");
+
+    println!("-- Network --");
+    let network = NetworkConfig {
+        default_interface: prompt_field("Default interface", &current.network.default_interface)?,
+        scan_threads: prompt_parsed("Scan threads", current.network.scan_threads)?,
+        timeout_seconds: prompt_parsed("Timeout (seconds)", current.network.timeout_seconds)?,
+        monitor_mode: prompt_bool("Enable monitor mode by default", current.network.monitor_mode)?,
+    };
+
+    println!("\n-- Security --");
+    let security = SecurityConfig {
+        vulnerability_db_path: prompt_field(
+            "Vulnerability DB path",
+            &current.security.vulnerability_db_path,
+        )?,
+        risk_threshold: prompt_field("Risk threshold", &current.security.risk_threshold)?,
+        enable_metasploit: prompt_bool("Enable Metasploit integration", current.security.enable_metasploit)?,
+        enable_nessus: prompt_bool("Enable Nessus integration", current.security.enable_nessus)?,
+    };
+
+    println!("\n-- Reporting --");
+    let reporting = ReportingConfig {
+        company_name: prompt_field("Company name", &current.reporting.company_name)?,
+        report_template: prompt_field("Report template", &current.reporting.report_template)?,
+        output_directory: prompt_field("Output directory", &current.reporting.output_directory)?,
+        enable_pdf: prompt_bool("Enable PDF reports", current.reporting.enable_pdf)?,
+        enable_html: prompt_bool("Enable HTML reports", current.reporting.enable_html)?,
+    };
+
+    println!("\n-- API --");
+    let api = ApiConfig {
+        metasploit_host: prompt_field("Metasploit host", &current.api.metasploit_host)?,
+        metasploit_port: prompt_parsed("Metasploit port", current.api.metasploit_port)?,
+        metasploit_user: prompt_field("Metasploit RPC username", &current.api.metasploit_user)?,
+        metasploit_password: prompt_field("Metasploit RPC password", &current.api.metasploit_password)?,
+        nessus_host: prompt_field("Nessus host", &current.api.nessus_host)?,
+        nessus_port: prompt_parsed("Nessus port", current.api.nessus_port)?,
+    };
+
+    let config = AppConfig {
+        network,
+        security,
+        reporting,
+        api,
+    };
+    config.validate()?;
+
+    Ok(config)
+}
@@ -0,0 +1,229 @@
+#![allow(unused_imports, dead_code, unused_variables)]
+use std::collections::HashMap;
+
+use rmpv::Value;
+
+use crate::config::{ApiConfig, AppConfig};
+
+/// Thin async client for msfrpcd's MessagePack-RPC protocol (`msgrpc`).
+/// Requests are `[method, token, args...]` msgpack arrays POSTed to
+/// `https://host:port/api/`; responses are msgpack maps, with an `error` key
+/// signalling failure.
+pub struct MetasploitClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl MetasploitClient {
+    pub fn new(host: &str, port: u16) -> Self {
+        let http = reqwest::Client::builder()
+            // msfrpcd ships with a self-signed cert by default.
+            .danger_accept_invalid_certs(true)
+            .build()
+            .expect("failed to build msfrpcd http client");
+
+        Self {
+            http,
+            base_url: format!("https://{}:{}/api/", host, port),
+            token: None,
+        }
+    }
+
+    /// Encodes `parts` as a msgpack array, POSTs it to msfrpcd, decodes the
+    /// response, and turns an `error` entry in the response map into `Err`.
+    async fn request(&self, parts: Vec<Value>) -> Result<Value, Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+        rmpv::encode::write_value(&mut buf, &Value::Array(parts))?;
+
+        let response = self
+            .http
+            .post(&self.base_url)
+            .header("Content-Type", "binary/message-pack")
+            .body(buf)
+            .send()
+            .await?;
+
+        let bytes = response.bytes().await?;
+        let value = rmpv::decode::read_value(&mut bytes.as_ref())?;
+
+        if let Some(pairs) = value.as_map() {
+            if let Some((_, error)) = pairs.iter().find(|(k, _)| k.as_str() == Some("error")) {
+                if error.as_bool() == Some(true) || error.as_str().is_some() {
+                    let message = pairs
+                        .iter()
+                        .find(|(k, _)| k.as_str() == Some("error_message"))
+                        .and_then(|(_, v)| v.as_str())
+                        .unwrap_or("unknown msfrpcd error");
+                    return Err(format!("msfrpcd error: {}", message).into());
+                }
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn token(&self) -> Result<Value, Box<dyn std::error::Error>> {
+        self.token
+            .as_deref()
+            .map(Value::from)
+            .ok_or_else(|| "not authenticated with msfrpcd: call login() first".into())
+    }
+
+    /// `auth.login`: exchanges credentials for a session token used by every
+    /// subsequent call.
+    pub async fn login(&mut self, username: &str, password: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let response = self
+            .request(vec![
+                Value::from("auth.login"),
+                Value::from(username),
+                Value::from(password),
+            ])
+            .await?;
+
+        let token = response
+            .as_map()
+            .and_then(|pairs| pairs.iter().find(|(k, _)| k.as_str() == Some("token")))
+            .and_then(|(_, v)| v.as_str())
+            .ok_or("auth.login response missing token")?
+            .to_string();
+
+        self.token = Some(token);
+        Ok(())
+    }
+
+    /// `module.exploits`: the names of every exploit module msfrpcd knows about.
+    pub async fn list_exploits(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let response = self
+            .request(vec![Value::from("module.exploits"), self.token()?])
+            .await?;
+
+        let modules = response
+            .as_map()
+            .and_then(|pairs| pairs.iter().find(|(k, _)| k.as_str() == Some("modules")))
+            .and_then(|(_, v)| v.as_array())
+            .ok_or("module.exploits response missing modules list")?;
+
+        Ok(modules.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+    }
+
+    /// `module.auxiliary`: the names of every auxiliary module msfrpcd knows
+    /// about (scanners, bruteforcers, etc. — distinct from `module.exploits`).
+    pub async fn list_auxiliary(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let response = self
+            .request(vec![Value::from("module.auxiliary"), self.token()?])
+            .await?;
+
+        let modules = response
+            .as_map()
+            .and_then(|pairs| pairs.iter().find(|(k, _)| k.as_str() == Some("modules")))
+            .and_then(|(_, v)| v.as_array())
+            .ok_or("module.auxiliary response missing modules list")?;
+
+        Ok(modules.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+    }
+
+    /// `module.options`: the option map for a given exploit/auxiliary module.
+    pub async fn module_options(
+        &self,
+        module_type: &str,
+        name: &str,
+    ) -> Result<HashMap<String, Value>, Box<dyn std::error::Error>> {
+        let response = self
+            .request(vec![
+                Value::from("module.options"),
+                self.token()?,
+                Value::from(module_type),
+                Value::from(name),
+            ])
+            .await?;
+
+        let pairs = response.as_map().ok_or("module.options response was not a map")?;
+        Ok(pairs
+            .iter()
+            .filter_map(|(k, v)| k.as_str().map(|k| (k.to_string(), v.clone())))
+            .collect())
+    }
+
+    /// `module.execute`: launches `name` with the given datastore options.
+    pub async fn execute(
+        &self,
+        module_type: &str,
+        name: &str,
+        opts: HashMap<String, String>,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        let opts_map = opts
+            .into_iter()
+            .map(|(k, v)| (Value::from(k), Value::from(v)))
+            .collect();
+
+        self.request(vec![
+            Value::from("module.execute"),
+            self.token()?,
+            Value::from(module_type),
+            Value::from(name),
+            Value::Map(opts_map),
+        ])
+        .await
+    }
+
+    /// `session.list`: currently open sessions, keyed by session id.
+    pub async fn sessions(&self) -> Result<Value, Box<dyn std::error::Error>> {
+        self.request(vec![Value::from("session.list"), self.token()?]).await
+    }
+}
+
+pub struct ExploitManager {
+    metasploit: Option<MetasploitClient>,
+}
+
+impl ExploitManager {
+    pub fn new() -> Self {
+        Self { metasploit: None }
+    }
+
+    async fn connected_client(
+        &mut self,
+        config: &AppConfig,
+    ) -> Result<&MetasploitClient, Box<dyn std::error::Error>> {
+        if !config.security.enable_metasploit {
+            return Err("Metasploit integration is disabled in config".into());
+        }
+
+        if self.metasploit.is_none() {
+            let mut client = MetasploitClient::new(&config.api.metasploit_host, config.api.metasploit_port);
+            client
+                .login(&config.api.metasploit_user, &config.api.metasploit_password)
+                .await?;
+            self.metasploit = Some(client);
+        }
+
+        Ok(self.metasploit.as_ref().unwrap())
+    }
+
+    /// Picks a browser exploit module and launches it through msfrpcd, for
+    /// the `12) Auto-exploit browser` menu command.
+    pub async fn auto_exploit_browser(&mut self, config: &AppConfig) -> Result<Value, Box<dyn std::error::Error>> {
+        let client = self.connected_client(config).await?;
+        let exploits = client.list_exploits().await?;
+        let target = exploits
+            .iter()
+            .find(|name| name.contains("browser"))
+            .ok_or("no browser exploit module available on this msfrpcd instance")?;
+
+        client.execute("exploit", target, HashMap::new()).await
+    }
+
+    /// Picks a login bruteforce auxiliary module and launches it through
+    /// msfrpcd, for the `13) Bruteforce login` menu command.
+    pub async fn bruteforce_login(&mut self, config: &AppConfig) -> Result<Value, Box<dyn std::error::Error>> {
+        let client = self.connected_client(config).await?;
+        let auxiliary = client.list_auxiliary().await?;
+        let target = auxiliary
+            .iter()
+            .find(|name| name.contains("login") || name.contains("brute"))
+            .ok_or("no login bruteforce module available on this msfrpcd instance")?;
+
+        client.execute("auxiliary", target, HashMap::new()).await
+    }
+}
@@ -1,5 +1,8 @@
 #![allow(unused_imports, dead_code, unused_variables)]
+use std::fs;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 mod network;
 mod security;
@@ -30,11 +33,12 @@ struct EhtoolsEnterprise {
     exploits: ExploitManager,
     reporter: ReportGenerator,
     config: AppConfig,
+    exploits_launched: Vec<String>,
 }
 
 impl EhtoolsEnterprise {
     fn new() -> Self {
-        let config = AppConfig::load().unwrap_or_default();
+        let config = AppConfig::load(None).unwrap_or_default();
         
         Self {
             info: ToolInfo {
@@ -50,6 +54,7 @@ impl EhtoolsEnterprise {
             exploits: ExploitManager::new(),
             reporter: ReportGenerator::new(),
             config,
+            exploits_launched: Vec::new(),
         }
     }
 
@@ -95,6 +100,7 @@ impl EhtoolsEnterprise {
         println!("║ 9) Find WPS pin    11) Ask (Howdoi tool)                       ║");
         println!("║ 10) MITM menu    12) Auto-exploit browser                      ║");
         println!("║ 0) Exit    13) Bruteforce login                                ║");
+        println!("║ 14) Generate report                                            ║");
         println!("╚══════════════════════════════════════════════════════════════╝");
     }
 
@@ -179,6 +185,9 @@ impl EhtoolsEnterprise {
             "13" => {
                 self.bruteforce_login().await;
             }
+            "14" => {
+                self.generate_report().await;
+            }
             _ => {
                 println!("❌ Unknown command: {}", command);
             }
@@ -253,8 +262,37 @@ impl EhtoolsEnterprise {
         println!("🔄 Checking for updates...");
     }
 
-    async fn settings_menu(&self) {
-        println!("⚙️ Opening settings menu...");
+    async fn settings_menu(&mut self) {
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                        SETTINGS MENU                        ║");
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║ w) Run configuration wizard                                    ║");
+        println!("║ v) View current config                                         ║");
+        println!("║ b) Back                                                        ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+        print!("(settings)> ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Failed to read line");
+
+        match input.trim().to_lowercase().as_str() {
+            "w" | "wizard" => self.run_wizard_and_save().await,
+            "v" | "view" => println!("{:#?}", self.config),
+            _ => {}
+        }
+    }
+
+    async fn run_wizard_and_save(&mut self) {
+        match config::run_wizard(&self.config).await {
+            Ok(new_config) => {
+                self.config = new_config;
+                if let Err(e) = self.config.save(None) {
+                    println!("❌ Failed to save config: {}", e);
+                }
+            }
+            Err(e) => println!("❌ Wizard failed: {}", e),
+        }
     }
 
     async fn view_public_ip(&self) {
@@ -267,10 +305,41 @@ impl EhtoolsEnterprise {
 
     async fn handshake(&self) {
         println!("🤝 Capturing handshake...");
+        match self.select_target_access_point().await {
+            Ok(Some(ap)) => println!(
+                "   targeting {} on channel {} ({:?})",
+                ap.ssid.as_deref().unwrap_or("<hidden>"),
+                ap.channel,
+                ap.auth_method
+            ),
+            Ok(None) => println!("   no access points found to target"),
+            Err(e) => println!("❌ Scan failed: {}", e),
+        }
     }
 
     async fn find_wps_pin(&self) {
         println!("📶 Finding WPS pin...");
+        match self.select_target_access_point().await {
+            Ok(Some(ap)) => println!(
+                "   targeting {} ({}) on channel {}",
+                ap.ssid.as_deref().unwrap_or("<hidden>"),
+                ap.bssid,
+                ap.channel
+            ),
+            Ok(None) => println!("   no access points found to target"),
+            Err(e) => println!("❌ Scan failed: {}", e),
+        }
+    }
+
+    /// Scans the configured default interface and picks the strongest-signal
+    /// access point as the target BSSID/channel for `handshake`/`find_wps_pin`.
+    async fn select_target_access_point(
+        &self,
+    ) -> Result<Option<network::AccessPoint>, Box<dyn std::error::Error>> {
+        let interface = &self.config.network.default_interface;
+        let mut access_points = self.network.scan_access_points(interface).await?;
+        access_points.sort_by_key(|ap| std::cmp::Reverse(ap.signal_dbm));
+        Ok(access_points.into_iter().next())
     }
 
     async fn mitm_menu(&self) {
@@ -281,18 +350,136 @@ impl EhtoolsEnterprise {
         println!("❓ Using Howdoi tool...");
     }
 
-    async fn auto_exploit_browser(&self) {
+    async fn auto_exploit_browser(&mut self) {
         println!("🌐 Auto-exploit browser...");
+        match self.exploits.auto_exploit_browser(&self.config).await {
+            Ok(result) => {
+                println!("   launched via Metasploit: {:?}", result);
+                self.exploits_launched.push(format!("auto-exploit-browser: {:?}", result));
+            }
+            Err(e) => println!("❌ Auto-exploit failed: {}", e),
+        }
     }
 
-    async fn bruteforce_login(&self) {
+    async fn bruteforce_login(&mut self) {
         println!("🔓 Bruteforce login...");
+        match self.exploits.bruteforce_login(&self.config).await {
+            Ok(result) => {
+                println!("   launched via Metasploit: {:?}", result);
+                self.exploits_launched.push(format!("bruteforce-login: {:?}", result));
+            }
+            Err(e) => println!("❌ Bruteforce failed: {}", e),
+        }
+    }
+
+    /// Scans the network, collects the results into a `ReportData`, and
+    /// renders it through `ReportGenerator` per the reporting config.
+    async fn generate_report(&self) {
+        println!("📝 Generating report...");
+
+        let interface = &self.config.network.default_interface;
+        let devices = match self.network.arp_scan(interface).await {
+            Ok(devices) => devices,
+            Err(e) => {
+                println!("❌ ARP scan failed: {}", e);
+                Vec::new()
+            }
+        };
+        let access_points = match self.network.scan_access_points(interface).await {
+            Ok(access_points) => access_points,
+            Err(e) => {
+                println!("❌ Access point scan failed: {}", e);
+                Vec::new()
+            }
+        };
+
+        let data = self
+            .reporter
+            .collect_report_data(devices, access_points, self.exploits_launched.clone())
+            .await;
+
+        match self.reporter.generate_report(&data, &self.config.reporting).await {
+            Ok(paths) if paths.is_empty() => {
+                println!("   enable_html/enable_pdf are both false in config; nothing written");
+            }
+            Ok(paths) => {
+                for path in paths {
+                    println!("   ✅ wrote {}", path.display());
+                }
+            }
+            Err(e) => println!("❌ Report generation failed: {}", e),
+        }
     }
 
     fn clear_screen() {
         print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
     }
 
+    /// First-run setup: creates the directories `AppConfig` points at, writes
+    /// a default config if none exists yet, reports any external tools the
+    /// other commands shell out to that are missing from `PATH`, and
+    /// optionally registers a systemd service.
+    pub async fn install(register_service: bool) -> Result<(), Box<dyn std::error::Error>> {
+        println!("🚀 Installing Entynet Hacker Tools...");
+
+        let config = AppConfig::load(None).unwrap_or_default();
+
+        for dir in [&config.reporting.output_directory, &config.security.vulnerability_db_path] {
+            fs::create_dir_all(dir)?;
+            println!("   ✅ created {}", dir);
+        }
+
+        let config_path = AppConfig::default_path();
+        if config_path.exists() {
+            println!("   ℹ️  config already exists at {}, leaving it as-is", config_path.display());
+        } else {
+            config.save(None)?;
+        }
+
+        let required_tools = [
+            "iw",
+            if cfg!(target_os = "windows") { "ipconfig" } else { "ifconfig" },
+            "anonsurf",
+        ];
+        for tool in required_tools {
+            match which(tool) {
+                Some(path) => println!("   ✅ {} found at {}", tool, path.display()),
+                None => println!("   ⚠️  {} not found on PATH; commands depending on it will fail", tool),
+            }
+        }
+
+        if register_service {
+            if cfg!(target_os = "linux") {
+                install_systemd_service()?;
+            } else {
+                println!("   ⚠️  service registration is only supported on Linux (systemd)");
+            }
+        }
+
+        println!("✅ Install complete.");
+        Ok(())
+    }
+
+    /// Reverses `install`: disables and removes the systemd service (if any)
+    /// and the saved config file. Leaves `output_directory` and
+    /// `vulnerability_db_path` in place since they may hold user data.
+    pub async fn uninstall() -> Result<(), Box<dyn std::error::Error>> {
+        println!("🧹 Uninstalling Entynet Hacker Tools...");
+
+        if cfg!(target_os = "linux") {
+            uninstall_systemd_service()?;
+        }
+
+        let config_path = AppConfig::default_path();
+        if config_path.exists() {
+            fs::remove_file(&config_path)?;
+            println!("   ✅ removed {}", config_path.display());
+        }
+
+        println!("✅ Uninstall complete.");
+        Ok(())
+    }
+
     pub async fn run(&mut self) {
         Self::clear_screen();
         self.display_header();
@@ -320,8 +507,90 @@ impl EhtoolsEnterprise {
     }
 }
 
+const SYSTEMD_UNIT_PATH: &str = "/etc/systemd/system/hyperzilla.service";
+
+/// Looks `tool` up on `PATH`, the way a shell would before exec'ing it.
+fn which(tool: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(tool))
+        .find(|candidate| candidate.is_file())
+}
+
+fn install_systemd_service() -> Result<(), Box<dyn std::error::Error>> {
+    let exe = std::env::current_exe()?;
+    let unit = format!(
+        "[Unit]\nDescription=Entynet Hacker Tools\nAfter=network.target\n\n[Service]\nExecStart={}\nRestart=on-failure\n\n[Install]\nWantedBy=multi-user.target\n",
+        exe.display()
+    );
+
+    fs::write(SYSTEMD_UNIT_PATH, unit)?;
+
+    let reload = Command::new("systemctl").arg("daemon-reload").status()?;
+    if !reload.success() {
+        return Err("systemctl daemon-reload failed".into());
+    }
+
+    let enable = Command::new("systemctl").args(["enable", "hyperzilla.service"]).status()?;
+    if !enable.success() {
+        return Err("systemctl enable failed".into());
+    }
+
+    println!("   ✅ registered systemd service at {}", SYSTEMD_UNIT_PATH);
+    Ok(())
+}
+
+fn uninstall_systemd_service() -> Result<(), Box<dyn std::error::Error>> {
+    if !Path::new(SYSTEMD_UNIT_PATH).exists() {
+        return Ok(());
+    }
+
+    let _ = Command::new("systemctl").args(["disable", "--now", "hyperzilla.service"]).status();
+    fs::remove_file(SYSTEMD_UNIT_PATH)?;
+    let _ = Command::new("systemctl").arg("daemon-reload").status();
+
+    println!("   ✅ removed systemd service");
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.iter().any(|a| a == "install") {
+        let register_service = args.iter().any(|a| a == "--service");
+        if let Err(e) = EhtoolsEnterprise::install(register_service).await {
+            eprintln!("❌ Install failed: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.iter().any(|a| a == "uninstall") {
+        if let Err(e) = EhtoolsEnterprise::uninstall().await {
+            eprintln!("❌ Uninstall failed: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.iter().any(|a| a == "--wizard") {
+        let current = AppConfig::load(None).unwrap_or_default();
+        match config::run_wizard(&current).await {
+            Ok(new_config) => {
+                if let Err(e) = new_config.save(None) {
+                    eprintln!("❌ Failed to save config: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("❌ Wizard failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     let mut app = EhtoolsEnterprise::new();
     app.run().await;
 }
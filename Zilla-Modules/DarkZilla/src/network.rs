@@ -8,6 +8,138 @@ pub struct NetworkDevice {
     pub hostname: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMethod {
+    Open,
+    Wep,
+    WpaPsk,
+    Wpa2Psk,
+    Wpa3Sae,
+    Wpa2Enterprise,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessPoint {
+    pub ssid: Option<String>,
+    pub bssid: String,
+    pub channel: u8,
+    pub signal_dbm: i32,
+    pub auth_method: AuthMethod,
+}
+
+/// Accumulates the bits of a single `BSS ...` block from `iw dev <iface> scan`
+/// output as they're parsed, so a well-formed `AccessPoint` can be built once
+/// the block ends.
+#[derive(Default)]
+struct ScanBlock {
+    bssid: Option<String>,
+    ssid: Option<String>,
+    freq: Option<u32>,
+    signal_dbm: Option<i32>,
+    has_privacy: bool,
+    has_wpa_ie: bool,
+    has_rsn_ie: bool,
+    auth_suites: Vec<String>,
+}
+
+impl ScanBlock {
+    fn into_access_point(self) -> Option<AccessPoint> {
+        let bssid = self.bssid?;
+        let channel = self.freq.map(freq_to_channel).unwrap_or(0);
+        let signal_dbm = self.signal_dbm.unwrap_or(0);
+
+        let auth_method = if self.has_rsn_ie {
+            if self.auth_suites.iter().any(|s| s == "SAE") {
+                AuthMethod::Wpa3Sae
+            } else if self.auth_suites.iter().any(|s| s.contains("802.1X")) {
+                AuthMethod::Wpa2Enterprise
+            } else {
+                AuthMethod::Wpa2Psk
+            }
+        } else if self.has_wpa_ie {
+            AuthMethod::WpaPsk
+        } else if self.has_privacy {
+            AuthMethod::Wep
+        } else {
+            AuthMethod::Open
+        };
+
+        Some(AccessPoint {
+            ssid: self.ssid.filter(|s| !s.is_empty()),
+            bssid,
+            channel,
+            signal_dbm,
+            auth_method,
+        })
+    }
+}
+
+/// Maps a 2.4GHz/5GHz center frequency (MHz) as reported by `iw` to its
+/// channel number. Unknown frequencies fall back to `0`.
+fn freq_to_channel(freq: u32) -> u8 {
+    match freq {
+        2412..=2472 => ((freq - 2412) / 5 + 1) as u8,
+        2484 => 14,
+        5000..=5895 => ((freq - 5000) / 5) as u8,
+        _ => 0,
+    }
+}
+
+fn parse_iw_scan_output(output: &str) -> Vec<AccessPoint> {
+    let mut access_points = Vec::new();
+    let mut current: Option<ScanBlock> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+
+        if let Some(rest) = line.strip_prefix("BSS ") {
+            if let Some(block) = current.take() {
+                if let Some(ap) = block.into_access_point() {
+                    access_points.push(ap);
+                }
+            }
+            let bssid = rest.split(['(', ' ']).next().unwrap_or("").to_string();
+            current = Some(ScanBlock {
+                bssid: Some(bssid),
+                ..ScanBlock::default()
+            });
+            continue;
+        }
+
+        let Some(block) = current.as_mut() else {
+            continue;
+        };
+
+        if let Some(freq) = trimmed.strip_prefix("freq: ") {
+            block.freq = freq.trim().parse().ok();
+        } else if let Some(signal) = trimmed.strip_prefix("signal: ") {
+            block.signal_dbm = signal
+                .split_whitespace()
+                .next()
+                .and_then(|v| v.parse::<f32>().ok())
+                .map(|v| v.round() as i32);
+        } else if let Some(ssid) = trimmed.strip_prefix("SSID: ") {
+            block.ssid = Some(ssid.to_string());
+        } else if trimmed.starts_with("capability:") && trimmed.contains("Privacy") {
+            block.has_privacy = true;
+        } else if trimmed.starts_with("WPA:") {
+            block.has_wpa_ie = true;
+        } else if trimmed.starts_with("RSN:") {
+            block.has_rsn_ie = true;
+        } else if let Some(suite) = trimmed.strip_prefix("* Authentication suites: ") {
+            block.auth_suites.push(suite.trim().to_string());
+        }
+    }
+
+    if let Some(block) = current.take() {
+        if let Some(ap) = block.into_access_point() {
+            access_points.push(ap);
+        }
+    }
+
+    access_points
+}
+
 pub struct NetworkManager;
 
 impl NetworkManager {
@@ -21,7 +153,7 @@ impl NetworkManager {
         } else {
             Command::new("ifconfig").output()?
         };
-        
+
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
@@ -38,10 +170,41 @@ impl NetworkManager {
                 hostname: Some("workstation".to_string()),
             },
         ];
-        
+
         Ok(devices)
     }
 
+    /// Scans for nearby access points on `interface` and returns them as
+    /// structured data instead of raw text. On Linux this shells out to
+    /// `iw dev <interface> scan` (requires the interface to be up and, for a
+    /// full scan, appropriate privileges) and parses the `BSS` blocks it
+    /// prints. Other platforms aren't wired up yet and return an error.
+    #[cfg(target_os = "linux")]
+    pub async fn scan_access_points(
+        &self,
+        interface: &str,
+    ) -> Result<Vec<AccessPoint>, Box<dyn std::error::Error>> {
+        let output = Command::new("iw")
+            .args(["dev", interface, "scan"])
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("iw scan failed on {}: {}", interface, stderr.trim()).into());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(parse_iw_scan_output(&stdout))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub async fn scan_access_points(
+        &self,
+        _interface: &str,
+    ) -> Result<Vec<AccessPoint>, Box<dyn std::error::Error>> {
+        Err("scan_access_points is only supported on Linux".into())
+    }
+
     pub async fn enable_wireless_interface(&self, interface: &str) {
         println!("Enabling wireless interface: {}", interface);
     }
@@ -58,3 +221,73 @@ impl NetworkManager {
         println!("Disabling monitor mode");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_SCAN: &str = "\
+BSS aa:bb:cc:dd:ee:ff(on wlan0)
+\tTSF: 123456 usec (0d, 00:00:01)
+\tfreq: 2437
+\tsignal: -42.00 dBm
+\tcapability: ESS Privacy ShortSlotTime (0x0411)
+\tSSID: HomeNetwork
+\tRSN:\t * Version: 1
+\t\t * Group cipher: CCMP
+\t\t * Pairwise ciphers: CCMP
+\t\t * Authentication suites: PSK
+BSS 11:22:33:44:55:66(on wlan0)
+\tfreq: 5180
+\tsignal: -60.00 dBm
+\tcapability: ESS ShortSlotTime (0x0401)
+\tSSID: OpenGuest
+BSS 22:33:44:55:66:77(on wlan0)
+\tfreq: 2462
+\tsignal: -70.00 dBm
+\tcapability: ESS Privacy ShortSlotTime (0x0411)
+\tSSID: SecureCorp
+\tRSN:\t * Version: 1
+\t\t * Authentication suites: SAE
+BSS 33:44:55:66:77:88(on wlan0)
+\tfreq: 2412
+\tsignal: -55.00 dBm
+\tcapability: ESS Privacy ShortSlotTime (0x0411)
+\tSSID: CorpWifi
+\tRSN:\t * Version: 1
+\t\t * Group cipher: CCMP
+\t\t * Pairwise ciphers: CCMP
+\t\t * Authentication suites: IEEE 802.1X
+";
+
+    #[test]
+    fn parses_wpa2_psk_network() {
+        let aps = parse_iw_scan_output(SAMPLE_SCAN);
+        let home = aps.iter().find(|ap| ap.bssid == "aa:bb:cc:dd:ee:ff").unwrap();
+        assert_eq!(home.ssid.as_deref(), Some("HomeNetwork"));
+        assert_eq!(home.channel, 6);
+        assert_eq!(home.signal_dbm, -42);
+        assert_eq!(home.auth_method, AuthMethod::Wpa2Psk);
+    }
+
+    #[test]
+    fn parses_open_network() {
+        let aps = parse_iw_scan_output(SAMPLE_SCAN);
+        let open = aps.iter().find(|ap| ap.bssid == "11:22:33:44:55:66").unwrap();
+        assert_eq!(open.auth_method, AuthMethod::Open);
+    }
+
+    #[test]
+    fn parses_wpa2_enterprise_network() {
+        let aps = parse_iw_scan_output(SAMPLE_SCAN);
+        let enterprise = aps.iter().find(|ap| ap.bssid == "33:44:55:66:77:88").unwrap();
+        assert_eq!(enterprise.auth_method, AuthMethod::Wpa2Enterprise);
+    }
+
+    #[test]
+    fn parses_wpa3_sae_network() {
+        let aps = parse_iw_scan_output(SAMPLE_SCAN);
+        let sae = aps.iter().find(|ap| ap.bssid == "22:33:44:55:66:77").unwrap();
+        assert_eq!(sae.auth_method, AuthMethod::Wpa3Sae);
+    }
+}
@@ -1,5 +1,61 @@
 #![allow(unused_imports, dead_code, unused_variables)]
-use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::Utc;
+
+use crate::config::ReportingConfig;
+use crate::network::{AccessPoint, AuthMethod, NetworkDevice};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    fn label(&self) -> &'static str {
+        match self {
+            Severity::Critical => "Critical",
+            Severity::High => "High",
+            Severity::Medium => "Medium",
+            Severity::Low => "Low",
+            Severity::Info => "Info",
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            Severity::Critical => "#7f1d1d",
+            Severity::High => "#b91c1c",
+            Severity::Medium => "#d97706",
+            Severity::Low => "#2563eb",
+            Severity::Info => "#6b7280",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub title: String,
+    pub severity: Severity,
+    pub description: String,
+}
+
+/// Structured input to the report templates, gathered by
+/// `collect_report_data` from the other subsystems' scan/exploit results.
+#[derive(Debug, Default)]
+pub struct ReportData {
+    pub scan_date: String,
+    pub devices: Vec<NetworkDevice>,
+    pub access_points: Vec<AccessPoint>,
+    pub findings: Vec<Finding>,
+    pub exploits_launched: Vec<String>,
+}
 
 pub struct ReportGenerator;
 
@@ -8,15 +64,258 @@ impl ReportGenerator {
         Self
     }
 
-    pub async fn collect_report_data(&self) -> HashMap<String, String> {
-        let mut data = HashMap::new();
-        data.insert("scan_date".to_string(), "2024-01-15".to_string());
-        data.insert("vulnerabilities_found".to_string(), "12".to_string());
-        data
+    /// Builds a `ReportData` from raw scan/exploit results, deriving one
+    /// `Finding` per access point that isn't on WPA2/WPA3.
+    pub async fn collect_report_data(
+        &self,
+        devices: Vec<NetworkDevice>,
+        access_points: Vec<AccessPoint>,
+        exploits_launched: Vec<String>,
+    ) -> ReportData {
+        let findings = access_points
+            .iter()
+            .filter_map(|ap| {
+                let (severity, description) = match ap.auth_method {
+                    AuthMethod::Open => (
+                        Severity::Critical,
+                        "Access point broadcasts with no encryption.".to_string(),
+                    ),
+                    AuthMethod::Wep => (
+                        Severity::High,
+                        "Access point uses WEP, which is trivially crackable.".to_string(),
+                    ),
+                    AuthMethod::WpaPsk => (
+                        Severity::Medium,
+                        "Access point uses WPA1, which is deprecated.".to_string(),
+                    ),
+                    AuthMethod::Wpa2Enterprise | AuthMethod::Wpa2Psk | AuthMethod::Wpa3Sae => return None,
+                };
+
+                Some(Finding {
+                    title: format!("Weak encryption on {}", ap.ssid.as_deref().unwrap_or(&ap.bssid)),
+                    severity,
+                    description,
+                })
+            })
+            .collect();
+
+        ReportData {
+            scan_date: current_timestamp(),
+            devices,
+            access_points,
+            findings,
+            exploits_launched,
+        }
+    }
+
+    /// Renders `data` through `config.report_template` (`"enterprise"` or
+    /// `"minimal"`) and writes whichever of HTML/PDF `config` has enabled
+    /// under `config.output_directory`, returning the paths written.
+    pub async fn generate_report(
+        &self,
+        data: &ReportData,
+        config: &ReportingConfig,
+    ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        if !config.enable_html && !config.enable_pdf {
+            return Ok(Vec::new());
+        }
+
+        fs::create_dir_all(&config.output_directory)?;
+
+        let html = render_html(data, config);
+        let stamp = filename_timestamp();
+        let mut written = Vec::new();
+
+        let html_path = Path::new(&config.output_directory).join(format!("report_{}.html", stamp));
+        if config.enable_html {
+            fs::write(&html_path, &html)?;
+            written.push(html_path.clone());
+        }
+
+        if config.enable_pdf {
+            if !config.enable_html {
+                // generate_pdf_report needs the rendered HTML on disk even
+                // when the caller doesn't want to keep it.
+                fs::write(&html_path, &html)?;
+            }
+            let pdf_path = Path::new(&config.output_directory).join(format!("report_{}.pdf", stamp));
+            self.generate_pdf_report(&html_path, &pdf_path)?;
+            written.push(pdf_path);
+            if !config.enable_html {
+                let _ = fs::remove_file(&html_path);
+            }
+        }
+
+        Ok(written)
     }
 
-    pub async fn generate_pdf_report(&self, _data: &HashMap<String, String>) -> Result<(), Box<dyn std::error::Error>> {
-        println!("Generating PDF report");
+    /// Shells out to `wkhtmltopdf` to turn a rendered HTML report into a PDF.
+    fn generate_pdf_report(&self, html_path: &Path, pdf_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let status = Command::new("wkhtmltopdf").arg(html_path).arg(pdf_path).status()?;
+
+        if !status.success() {
+            return Err("wkhtmltopdf failed to render the PDF report".into());
+        }
+
         Ok(())
     }
 }
+
+/// Human-readable `YYYY-MM-DD HH:MM:SS` timestamp for the report header.
+fn current_timestamp() -> String {
+    Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Filesystem-safe variant of `current_timestamp` for report filenames.
+fn filename_timestamp() -> String {
+    Utc::now().format("%Y%m%d_%H%M%S").to_string()
+}
+
+fn escape_html(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn findings_table(data: &ReportData) -> String {
+    let rows: String = data
+        .findings
+        .iter()
+        .map(|f| {
+            format!(
+                "<tr><td style=\"background-color:{};color:#fff\">{}</td><td>{}</td><td>{}</td></tr>",
+                f.severity.color(),
+                f.severity.label(),
+                escape_html(&f.title),
+                escape_html(&f.description)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<table><thead><tr><th>Severity</th><th>Finding</th><th>Description</th></tr></thead><tbody>{}</tbody></table>",
+        rows
+    )
+}
+
+fn devices_table(data: &ReportData) -> String {
+    let rows: String = data
+        .devices
+        .iter()
+        .map(|d| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape_html(&d.ip),
+                escape_html(&d.mac),
+                escape_html(d.hostname.as_deref().unwrap_or("-"))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<table><thead><tr><th>IP</th><th>MAC</th><th>Hostname</th></tr></thead><tbody>{}</tbody></table>",
+        rows
+    )
+}
+
+fn access_points_table(data: &ReportData) -> String {
+    let rows: String = data
+        .access_points
+        .iter()
+        .map(|ap| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{} dBm</td><td>{:?}</td></tr>",
+                escape_html(ap.ssid.as_deref().unwrap_or("<hidden>")),
+                escape_html(&ap.bssid),
+                ap.channel,
+                ap.signal_dbm,
+                ap.auth_method
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<table><thead><tr><th>SSID</th><th>BSSID</th><th>Channel</th><th>Signal</th><th>Auth</th></tr></thead><tbody>{}</tbody></table>",
+        rows
+    )
+}
+
+fn render_html(data: &ReportData, config: &ReportingConfig) -> String {
+    match config.report_template.as_str() {
+        "minimal" => render_minimal(data, config),
+        _ => render_enterprise(data, config),
+    }
+}
+
+fn render_enterprise(data: &ReportData, config: &ReportingConfig) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{company} — Penetration Test Report</title>
+<style>
+body {{ font-family: "Segoe UI", Arial, sans-serif; margin: 2rem; color: #1f2937; }}
+h1 {{ color: #111827; border-bottom: 3px solid #1d4ed8; padding-bottom: 0.5rem; }}
+h2 {{ color: #1d4ed8; margin-top: 2rem; }}
+table {{ width: 100%; border-collapse: collapse; margin: 1rem 0; }}
+th, td {{ border: 1px solid #d1d5db; padding: 0.5rem; text-align: left; }}
+th {{ background-color: #1d4ed8; color: #fff; }}
+.meta {{ color: #6b7280; }}
+</style>
+</head>
+<body>
+<h1>{company} — Penetration Test Report</h1>
+<p class="meta">Generated {scan_date}</p>
+
+<h2>Findings</h2>
+{findings}
+
+<h2>Discovered Devices</h2>
+{devices}
+
+<h2>Access Points</h2>
+{access_points}
+
+<h2>Exploits Launched</h2>
+<ul>{exploits}</ul>
+</body>
+</html>"#,
+        company = escape_html(&config.company_name),
+        scan_date = escape_html(&data.scan_date),
+        findings = findings_table(data),
+        devices = devices_table(data),
+        access_points = access_points_table(data),
+        exploits = data
+            .exploits_launched
+            .iter()
+            .map(|e| format!("<li>{}</li>", escape_html(e)))
+            .collect::<Vec<_>>()
+            .join(""),
+    )
+}
+
+fn render_minimal(data: &ReportData, config: &ReportingConfig) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>{company} Report</title></head>
+<body>
+<h1>{company} Report — {scan_date}</h1>
+{findings}
+{devices}
+{access_points}
+</body>
+</html>"#,
+        company = escape_html(&config.company_name),
+        scan_date = escape_html(&data.scan_date),
+        findings = findings_table(data),
+        devices = devices_table(data),
+        access_points = access_points_table(data),
+    )
+}